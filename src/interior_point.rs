@@ -0,0 +1,139 @@
+///
+/// ## Overview
+///
+/// A sibling to `Centroid` for callers (label placement, tooltip anchoring)
+/// that need a point guaranteed to intersect the geometry, rather than a
+/// balance point that can land outside it for concave or C-shaped polygons.
+
+use lodestone_linestring::FeatureLineString;
+use lodestone_point::FeaturePoint;
+use lodestone_polygon::FeaturePolygon;
+
+use super::{bounding_box, coords_distance, Centroid};
+
+pub trait InteriorPoint {
+  fn interior_point(&self) -> FeaturePoint;
+}
+
+/// The x-coordinates where the horizontal line `y = scan_y` crosses an edge
+/// of `ring`.
+fn scanline_crossings(ring: &[Vec<f64>], scan_y: f64) -> Vec<f64> {
+  let mut crossings = Vec::new();
+  let mut prev = &ring[ring.len() - 1];
+
+  for point in ring {
+    let (y0, y1) = (prev[1], point[1]);
+
+    if (y0 <= scan_y && y1 > scan_y) || (y1 <= scan_y && y0 > scan_y) {
+      let t = (scan_y - y0) / (y1 - y0);
+      crossings.push(prev[0] + t * (point[0] - prev[0]));
+    }
+
+    prev = point;
+  }
+
+  crossings
+}
+
+impl InteriorPoint for FeaturePolygon {
+  /// Bisects the polygon's bounding box with the horizontal scan line
+  /// `y = (ymin+ymax)/2`, intersects it with every ring's edges, pairs the
+  /// crossings into interior spans by even-odd parity, and returns the
+  /// midpoint of the widest span.
+  fn interior_point(&self) -> FeaturePoint {
+    let rings = self.coordinates();
+    let (_, _, y_min, y_max) = bounding_box(&rings[0]);
+    let scan_y = (y_min + y_max) / 2.0;
+
+    let mut crossings: Vec<f64> = rings.iter()
+      .flat_map(|ring| scanline_crossings(ring, scan_y))
+      .collect();
+    crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    if crossings.len() < 2 {
+      // A flat/collinear ring (y_min == y_max) crosses the scan line nowhere;
+      // fall back to the polygon's own (possibly line-fallback) centroid.
+      return self.centroid().expect("non-empty polygon has a centroid");
+    }
+
+    let mut best_span = (crossings[0], crossings[1]);
+    let mut best_width = coords_distance(&vec![best_span.0, scan_y], &vec![best_span.1, scan_y]);
+
+    for span in crossings.chunks(2).skip(1) {
+      if span.len() < 2 {
+        continue;
+      }
+
+      let width = coords_distance(&vec![span[0], scan_y], &vec![span[1], scan_y]);
+
+      if width > best_width {
+        best_width = width;
+        best_span = (span[0], span[1]);
+      }
+    }
+
+    FeaturePoint::new(vec![(best_span.0 + best_span.1) / 2.0, scan_y])
+  }
+}
+
+impl InteriorPoint for FeatureLineString {
+  /// Returns the non-endpoint vertex nearest the line's centroid, or an
+  /// endpoint if the line has only two points.
+  fn interior_point(&self) -> FeaturePoint {
+    let coords = self.coordinates();
+
+    if coords.len() <= 2 {
+      return FeaturePoint::new(coords[0].clone());
+    }
+
+    let centroid = self.centroid().expect("non-empty linestring has a centroid");
+    let interior = &coords[1..coords.len() - 1];
+
+    let nearest = interior.iter()
+      .min_by(|a, b| {
+        coords_distance(a, &centroid.coordinates())
+          .partial_cmp(&coords_distance(b, &centroid.coordinates()))
+          .unwrap()
+      })
+      .unwrap();
+
+    FeaturePoint::new(nearest.clone())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use lodestone_point::FeaturePoint;
+  use lodestone_polygon::FeaturePolygon;
+  use super::InteriorPoint;
+
+  #[test]
+  fn test_polygon_with_hole() {
+    // A hole off-center to the left splits the y=5 scan line into a narrow
+    // span to the left of the hole and a wide span to the right of it; the
+    // widest-span rule should pick the point to the right of the hole.
+    let exterior = vec![vec![0.0, 0.0], vec![10.0, 0.0], vec![10.0, 10.0], vec![0.0, 10.0], vec![0.0, 0.0]];
+    let hole = vec![vec![1.0, 4.0], vec![3.0, 4.0], vec![3.0, 6.0], vec![1.0, 6.0], vec![1.0, 4.0]];
+    let polygon = FeaturePolygon::new(vec![exterior, hole]);
+
+    let point = polygon.interior_point();
+
+    assert_eq!(point, FeaturePoint::new(vec![6.5, 5.0]));
+  }
+
+  #[test]
+  fn test_concave_c_shape() {
+    // A "C" shape open to the left: its plain area centroid falls inside the
+    // notch (outside the polygon), so interior_point must land on the solid
+    // connector bar instead.
+    let ring = vec![
+      vec![0.0, 0.0], vec![10.0, 0.0], vec![10.0, 10.0], vec![0.0, 10.0],
+      vec![0.0, 6.0], vec![6.0, 6.0], vec![6.0, 4.0], vec![0.0, 4.0], vec![0.0, 0.0],
+    ];
+    let polygon = FeaturePolygon::new(vec![ring]);
+
+    let point = polygon.interior_point();
+
+    assert_eq!(point, FeaturePoint::new(vec![8.0, 5.0]));
+  }
+}
@@ -7,11 +7,22 @@
 
 // Third party crates
 extern crate lodestone_along;
+extern crate lodestone_geometrycollection;
 extern crate lodestone_line_distance;
 extern crate lodestone_linestring;
+extern crate lodestone_multilinestring;
+extern crate lodestone_multipoint;
+extern crate lodestone_multipolygon;
 extern crate lodestone_point;
 extern crate lodestone_polygon;
 
+mod interior_point;
+mod multi;
+mod pole_of_inaccessibility;
+
+pub use interior_point::InteriorPoint;
+pub use pole_of_inaccessibility::pole_of_inaccessibility;
+
 use lodestone_along::Along;
 use lodestone_line_distance::LineDistance;
 use lodestone_linestring::FeatureLineString;
@@ -19,44 +30,145 @@ use lodestone_point::FeaturePoint;
 use lodestone_polygon::FeaturePolygon;
 
 pub trait Centroid {
-  fn centroid(&self) -> FeaturePoint;
+  fn centroid(&self) -> Option<FeaturePoint>;
+}
+
+/// Computes the shoelace sums for a single ring: `6 * signed area`, and the
+/// `x`/`y` sums used to derive that ring's centroid (`sum / (6 * signed area)`).
+pub(crate) fn ring_shoelace_sums(ring: &[Vec<f64>]) -> (f64, f64, f64) {
+  let mut ring = ring.to_vec();
+  let mut area = 0.0;
+  let mut x_sum = 0.0;
+  let mut y_sum = 0.0;
+
+  let mut prev = ring.remove(0);
+
+  for coord in ring {
+    let f = coord[1] * prev[0] - prev[1] * coord[0];
+
+    x_sum += (coord[0] + prev[0]) * f;
+    y_sum += (coord[1] + prev[1]) * f;
+    area += f * 3.0;
+
+    // set up for the next iteration
+    prev = coord.clone();
+  }
+
+  (area, x_sum, y_sum)
+}
+
+/// A polygon's area: the exterior ring's area minus every interior ring's
+/// (hole's), the same hole accounting `FeaturePolygon::centroid` uses to
+/// weight holes out of the balance point. Used to weight a polygon among
+/// siblings (e.g. in a MultiPolygon) rather than by its exterior ring alone.
+pub(crate) fn polygon_area(rings: &[Vec<Vec<f64>>]) -> f64 {
+  let (area, _, _) = ring_shoelace_sums(&rings[0]);
+  let mut area_total = (area / 6.0).abs();
+
+  for hole in rings.iter().skip(1) {
+    if hole.is_empty() {
+      continue;
+    }
+
+    let (area, _, _) = ring_shoelace_sums(hole);
+    area_total -= (area / 6.0).abs();
+  }
+
+  area_total
+}
+
+/// The `[x_min, x_max, y_min, y_max]` bounding box enclosing `ring`.
+pub(crate) fn bounding_box(ring: &[Vec<f64>]) -> (f64, f64, f64, f64) {
+  let mut x_min = ring[0][0];
+  let mut x_max = ring[0][0];
+  let mut y_min = ring[0][1];
+  let mut y_max = ring[0][1];
+
+  for point in ring.iter() {
+    x_min = x_min.min(point[0]);
+    x_max = x_max.max(point[0]);
+    y_min = y_min.min(point[1]);
+    y_max = y_max.max(point[1]);
+  }
+
+  (x_min, x_max, y_min, y_max)
+}
+
+/// Distance in meters between two coordinate pairs, via the same
+/// [`LineDistance`] edge math used elsewhere in this crate.
+pub(crate) fn coords_distance(a: &[f64], b: &[f64]) -> f64 {
+  FeatureLineString::new(vec![a.to_vec(), b.to_vec()]).distance("m")
 }
 
 impl Centroid for FeaturePolygon {
-  /// Calculates the centroid of a FeaturePolygon. This only utilizes the 
-  /// outer ring if there are multiple. Usable for non-intersecting polygons.
-  /// 
+  /// Calculates the centroid of a FeaturePolygon, weighting the exterior ring
+  /// against every interior ring (hole) so the result accounts for the mass
+  /// removed by each hole. Returns `None` if the exterior ring has no
+  /// coordinates. An empty interior ring contributes no area and is skipped
+  /// rather than treated as a hole. Falls back to the length-weighted
+  /// centroid of the exterior ring, treated as a line, when the weighted
+  /// area comes out to zero (a flat/collinear ring, or holes that fully
+  /// cancel the exterior).
+  ///
   /// Inspired by [L.Polygon::getCenter](https://github.com/Leaflet/Leaflet/blob/cca6e6165fbb0e2c543336bdcc976fc8f82db20a/src/layer/vector/Polygon.js)
-  fn centroid(&self) -> FeaturePoint {
+  fn centroid(&self) -> Option<FeaturePoint> {
 
-    let mut area = 0.0;
-    let mut x_sum = 0.0; 
-    let mut y_sum = 0.0;
+    let rings = self.coordinates();
+    let exterior = rings.first()?;
 
-    let mut ring = self.coordinates().first().unwrap().to_vec();
-    let mut prev = ring.remove(0);
-    
-    for coord in ring {
-      let f = coord[1] * prev[0] - prev[1] * coord[0];
-      
-      x_sum += (coord[0] + prev[0]) * f;
-      y_sum += (coord[1] + prev[1]) * f;
-      area += f * 3.0;
+    if exterior.is_empty() {
+      return None;
+    }
+
+    let (area, x_sum, y_sum) = ring_shoelace_sums(exterior);
+    let mut x_weighted = (x_sum / area) * (area / 6.0).abs();
+    let mut y_weighted = (y_sum / area) * (area / 6.0).abs();
+
+    for hole in rings.iter().skip(1) {
+      if hole.is_empty() {
+        continue;
+      }
+
+      let (area, x_sum, y_sum) = ring_shoelace_sums(hole);
+      let area_abs = (area / 6.0).abs();
+
+      if area_abs == 0.0 {
+        continue;
+      }
 
-      // set up for the next iteration
-      prev = coord.clone();
+      x_weighted -= (x_sum / area) * area_abs;
+      y_weighted -= (y_sum / area) * area_abs;
     }
 
-    FeaturePoint::new(vec![x_sum / area, y_sum / area])
+    let area_total = polygon_area(&rings);
+
+    if area_total == 0.0 {
+      return FeatureLineString::new(exterior.to_vec()).centroid();
+    }
+
+    Some(FeaturePoint::new(vec![x_weighted / area_total, y_weighted / area_total]))
   }
 }
 
 impl Centroid for FeatureLineString {
-  /// Calculates the centroid of a FeatureLineString. This only utilizes the 
-  /// outer ring if there are multiple. 
-  fn centroid(&self) -> FeaturePoint {
-    let half_distance = self.distance("m") / 2.0;
-    self.along(half_distance, "m")
+  /// Calculates the centroid of a FeatureLineString. This only utilizes the
+  /// outer ring if there are multiple. Returns `None` if there are no
+  /// coordinates, and the single coincident point if every vertex sits at the
+  /// same location (zero total length).
+  fn centroid(&self) -> Option<FeaturePoint> {
+    let coords = self.coordinates();
+
+    if coords.is_empty() {
+      return None;
+    }
+
+    let total_distance = self.distance("m");
+
+    if total_distance == 0.0 {
+      return Some(FeaturePoint::new(coords[0].clone()));
+    }
+
+    Some(self.along(total_distance / 2.0, "m"))
   }
 }
 
@@ -64,6 +176,7 @@ impl Centroid for FeatureLineString {
 mod tests {
 
   mod tests_poly {
+    use lodestone_linestring::FeatureLineString;
     use lodestone_point::FeaturePoint;
     use lodestone_polygon::FeaturePolygon;
     use super::super::Centroid;
@@ -96,12 +209,57 @@ mod tests {
       run_poly_test(ring, expected);
     }
 
+    #[test]
+    fn test_square_with_off_center_hole() {
+      // The hole sits in a corner rather than concentric with the exterior,
+      // so a sign error or dropped hole-subtraction term would pull this
+      // away from (5.125, 5.125) rather than leaving it coincidentally
+      // unchanged (as a centered hole would).
+      let exterior = vec![vec![0.0, 0.0], vec![10.0, 0.0], vec![10.0, 10.0], vec![0.0, 10.0], vec![0.0, 0.0]];
+      let hole = vec![vec![1.0, 1.0], vec![3.0, 1.0], vec![3.0, 3.0], vec![1.0, 3.0], vec![1.0, 1.0]];
+      let poly = FeaturePolygon::new(vec![exterior, hole]);
+      let expected = FeaturePoint::new(vec![5.125, 5.125]);
+
+      assert_eq!(poly.centroid(), Some(expected));
+    }
+
+    #[test]
+    fn test_empty_interior_ring_is_skipped_not_panicked_on() {
+      // An empty hole ring carries no area and must be skipped, the same way
+      // an empty exterior ring is; previously this panicked in
+      // `ring_shoelace_sums`'s `ring.remove(0)` on the empty Vec.
+      let exterior = vec![vec![0.0, 0.0], vec![2.0, 0.0], vec![2.0, 2.0], vec![0.0, 2.0], vec![0.0, 0.0]];
+      let poly = FeaturePolygon::new(vec![exterior, vec![]]);
+      let expected = FeaturePoint::new(vec![1.0, 1.0]);
+
+      assert_eq!(poly.centroid(), Some(expected));
+    }
+
+    #[test]
+    fn test_empty_exterior_returns_none() {
+      let poly = FeaturePolygon::new(vec![vec![]]);
+
+      assert_eq!(poly.centroid(), None);
+    }
+
+    #[test]
+    fn test_collinear_ring_falls_back_to_line_centroid() {
+      // Every vertex sits on `y = 0`, so the ring's signed area is zero and
+      // `centroid` must fall back to treating it as a line rather than
+      // dividing by that zero area.
+      let ring = vec![vec![0.0, 0.0], vec![2.0, 0.0], vec![4.0, 0.0], vec![0.0, 0.0]];
+      let poly = FeaturePolygon::new(vec![ring.clone()]);
+      let line = FeatureLineString::new(ring);
+
+      assert_eq!(poly.centroid(), line.centroid());
+    }
+
     // Helper method to test a polygon's centroid against an expected value
     fn run_poly_test(ring: Vec<Vec<f64>>, expected: Vec<f64>) -> () {
       let poly = FeaturePolygon::new(vec![ring]);
       let expected = FeaturePoint::new(expected);
 
-      assert_eq!(poly.centroid(), expected);
+      assert_eq!(poly.centroid(), Some(expected));
     }
   }
 
@@ -110,6 +268,13 @@ mod tests {
     use lodestone_linestring::FeatureLineString;
     use super::super::Centroid;
 
+    #[test]
+    fn test_empty_coords_returns_none() {
+      let line = FeatureLineString::new(vec![]);
+
+      assert_eq!(line.centroid(), None);
+    }
+
     #[test]
     fn test_simple() {
       let coords = vec![vec![0.0, 0.0], vec![0.0, 1.0], vec![1.0, 1.0], vec![1.0, 2.0]];
@@ -124,7 +289,7 @@ mod tests {
       let line = FeatureLineString::new(coords);
       let expected = FeaturePoint::new(expected);
 
-      assert_eq!(line.centroid(), expected);
+      assert_eq!(line.centroid(), Some(expected));
     }
   }
 }
@@ -0,0 +1,278 @@
+///
+/// ## Overview
+///
+/// Centroid implementations for the multi/collection feature types
+/// (FeatureMultiPoint, FeatureMultiLineString, FeatureMultiPolygon,
+/// FeatureGeometryCollection), following the standard dimension-hierarchy
+/// rule: points are weighted by count, lines by length, polygons by area.
+
+use lodestone_geometrycollection::{FeatureGeometryCollection, Geometry};
+use lodestone_line_distance::LineDistance;
+use lodestone_linestring::FeatureLineString;
+use lodestone_multilinestring::FeatureMultiLineString;
+use lodestone_multipoint::FeatureMultiPoint;
+use lodestone_multipolygon::FeatureMultiPolygon;
+use lodestone_point::FeaturePoint;
+use lodestone_polygon::FeaturePolygon;
+
+use super::{polygon_area, Centroid};
+
+/// Arithmetic mean of a list of points, weighted by count. Returns `None` for
+/// an empty list.
+fn multipoint_weighted_centroid(points: &[Vec<f64>]) -> Option<(FeaturePoint, f64)> {
+  if points.is_empty() {
+    return None;
+  }
+
+  let count = points.len() as f64;
+  let x_sum: f64 = points.iter().map(|point| point[0]).sum();
+  let y_sum: f64 = points.iter().map(|point| point[1]).sum();
+
+  Some((FeaturePoint::new(vec![x_sum / count, y_sum / count]), count))
+}
+
+/// Mean of each component line's centroid, weighted by that component's
+/// length. Degenerate (zero-length) members are ignored unless every member
+/// is degenerate, in which case this falls back to the mean of the
+/// coincident points, with a weight (total length) of zero.
+fn multilinestring_weighted_centroid(lines: &[Vec<Vec<f64>>]) -> Option<(FeaturePoint, f64)> {
+  let mut x_weighted = 0.0;
+  let mut y_weighted = 0.0;
+  let mut length_total = 0.0;
+  let mut degenerate_points: Vec<Vec<f64>> = Vec::new();
+
+  for coords in lines {
+    if coords.is_empty() {
+      continue;
+    }
+
+    let line = FeatureLineString::new(coords.clone());
+    let length = line.distance("m");
+
+    let centroid = match line.centroid() {
+      Some(point) => point,
+      None => continue,
+    };
+
+    if length == 0.0 {
+      degenerate_points.push(centroid.coordinates());
+      continue;
+    }
+
+    x_weighted += centroid.coordinates()[0] * length;
+    y_weighted += centroid.coordinates()[1] * length;
+    length_total += length;
+  }
+
+  if length_total > 0.0 {
+    return Some((FeaturePoint::new(vec![x_weighted / length_total, y_weighted / length_total]), length_total));
+  }
+
+  multipoint_weighted_centroid(&degenerate_points).map(|(point, _)| (point, 0.0))
+}
+
+/// Area-weighted mean of each component polygon's centroid. Members whose
+/// area (exterior minus holes, see `polygon_area`) is zero are ignored unless
+/// every member is degenerate, in which case this falls back to the mean of
+/// their (already degenerate) centroids, with a weight (total area) of zero.
+fn multipolygon_weighted_centroid(polygons: &[Vec<Vec<Vec<f64>>>]) -> Option<(FeaturePoint, f64)> {
+  let mut weighted: Vec<(FeaturePoint, f64)> = Vec::new();
+
+  for rings in polygons {
+    if rings.is_empty() || rings[0].is_empty() {
+      continue;
+    }
+
+    let polygon = FeaturePolygon::new(rings.clone());
+    let centroid = match polygon.centroid() {
+      Some(point) => point,
+      None => continue,
+    };
+
+    weighted.push((centroid, polygon_area(rings)));
+  }
+
+  if weighted.is_empty() {
+    return None;
+  }
+
+  let area_total: f64 = weighted.iter().map(|(_, area)| *area).sum();
+
+  if area_total == 0.0 {
+    let count = weighted.len() as f64;
+    let x_sum: f64 = weighted.iter().map(|(point, _)| point.coordinates()[0]).sum();
+    let y_sum: f64 = weighted.iter().map(|(point, _)| point.coordinates()[1]).sum();
+    return Some((FeaturePoint::new(vec![x_sum / count, y_sum / count]), 0.0));
+  }
+
+  let x_weighted: f64 = weighted.iter().map(|(point, area)| point.coordinates()[0] * area).sum();
+  let y_weighted: f64 = weighted.iter().map(|(point, area)| point.coordinates()[1] * area).sum();
+
+  Some((FeaturePoint::new(vec![x_weighted / area_total, y_weighted / area_total]), area_total))
+}
+
+impl Centroid for FeatureMultiPoint {
+  /// Calculates the centroid of a FeatureMultiPoint as the plain arithmetic
+  /// mean of its points. Returns `None` if there are no points.
+  fn centroid(&self) -> Option<FeaturePoint> {
+    multipoint_weighted_centroid(&self.coordinates()).map(|(point, _)| point)
+  }
+}
+
+impl Centroid for FeatureMultiLineString {
+  /// Calculates the centroid of a FeatureMultiLineString as the length-weighted
+  /// mean of its components' centroids. See `multilinestring_weighted_centroid`
+  /// for how degenerate (single-point) members are handled.
+  fn centroid(&self) -> Option<FeaturePoint> {
+    multilinestring_weighted_centroid(&self.coordinates()).map(|(point, _)| point)
+  }
+}
+
+impl Centroid for FeatureMultiPolygon {
+  /// Calculates the centroid of a FeatureMultiPolygon as the area-weighted
+  /// mean of its components' centroids. See `multipolygon_weighted_centroid`
+  /// for how degenerate (zero-area) members are handled.
+  fn centroid(&self) -> Option<FeaturePoint> {
+    multipolygon_weighted_centroid(&self.coordinates()).map(|(point, _)| point)
+  }
+}
+
+/// The dimension of a geometry for the purposes of combining a
+/// GeometryCollection: 2 for (multi)polygons, 1 for (multi)linestrings, 0 for
+/// (multi)points.
+fn geometry_dimension(geometry: &Geometry) -> u8 {
+  match *geometry {
+    Geometry::Polygon(_) | Geometry::MultiPolygon(_) => 2,
+    Geometry::LineString(_) | Geometry::MultiLineString(_) => 1,
+    Geometry::Point(_) | Geometry::MultiPoint(_) => 0,
+  }
+}
+
+/// The centroid and dimensional weight (area, length, or count) of a single
+/// member of a GeometryCollection.
+fn geometry_weighted_centroid(geometry: &Geometry) -> Option<(FeaturePoint, f64)> {
+  match *geometry {
+    Geometry::Point(ref point) => Some((point.clone(), 1.0)),
+    Geometry::MultiPoint(ref multi) => multipoint_weighted_centroid(&multi.coordinates()),
+    Geometry::LineString(ref line) => line.centroid().map(|point| (point, line.distance("m"))),
+    Geometry::MultiLineString(ref multi) => multilinestring_weighted_centroid(&multi.coordinates()),
+    Geometry::Polygon(ref polygon) => polygon.centroid().map(|point| {
+      (point, polygon_area(&polygon.coordinates()))
+    }),
+    Geometry::MultiPolygon(ref multi) => multipolygon_weighted_centroid(&multi.coordinates()),
+  }
+}
+
+impl Centroid for FeatureGeometryCollection {
+  /// Calculates the centroid of a FeatureGeometryCollection by finding the
+  /// maximum dimension present (2 for any polygon, else 1 for any line, else
+  /// 0 for points) and combining only the members of that dimension,
+  /// weighting by area, length, or count respectively.
+  fn centroid(&self) -> Option<FeaturePoint> {
+    let geometries = self.geometries();
+    let dimension = geometries.iter().map(geometry_dimension).max()?;
+
+    let mut x_weighted = 0.0;
+    let mut y_weighted = 0.0;
+    let mut weight_total = 0.0;
+
+    for geometry in geometries.iter().filter(|geometry| geometry_dimension(geometry) == dimension) {
+      if let Some((centroid, weight)) = geometry_weighted_centroid(geometry) {
+        x_weighted += centroid.coordinates()[0] * weight;
+        y_weighted += centroid.coordinates()[1] * weight;
+        weight_total += weight;
+      }
+    }
+
+    if weight_total == 0.0 {
+      return None;
+    }
+
+    Some(FeaturePoint::new(vec![x_weighted / weight_total, y_weighted / weight_total]))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use lodestone_geometrycollection::{FeatureGeometryCollection, Geometry};
+  use lodestone_linestring::FeatureLineString;
+  use lodestone_multilinestring::FeatureMultiLineString;
+  use lodestone_multipoint::FeatureMultiPoint;
+  use lodestone_multipolygon::FeatureMultiPolygon;
+  use lodestone_point::FeaturePoint;
+  use lodestone_polygon::FeaturePolygon;
+  use super::super::Centroid;
+
+  #[test]
+  fn test_multipoint() {
+    let points = vec![vec![0.0, 0.0], vec![2.0, 0.0], vec![2.0, 2.0], vec![0.0, 2.0]];
+    let multipoint = FeatureMultiPoint::new(points);
+    let expected = FeaturePoint::new(vec![1.0, 1.0]);
+
+    assert_eq!(multipoint.centroid(), Some(expected));
+  }
+
+  #[test]
+  fn test_multilinestring_weighted_by_length() {
+    // A long line should dominate a much shorter one in the length-weighted
+    // mean, landing the combined centroid close to the long line's own.
+    let long_line = vec![vec![0.0, 0.0], vec![10.0, 0.0]];
+    let short_line = vec![vec![50.0, 50.0], vec![50.001, 50.0]];
+
+    let long_centroid = FeatureLineString::new(long_line.clone()).centroid().unwrap().coordinates();
+    let multiline = FeatureMultiLineString::new(vec![long_line, short_line]);
+    let combined = multiline.centroid().unwrap().coordinates();
+
+    assert!((combined[0] - long_centroid[0]).abs() < 0.1, "got {:?}", combined);
+    assert!((combined[1] - long_centroid[1]).abs() < 0.1, "got {:?}", combined);
+  }
+
+  #[test]
+  fn test_multilinestring_all_degenerate_falls_back_to_point_mean() {
+    // When every member is a single coincident point (zero length), fall
+    // back to the plain mean of those points.
+    let lines = vec![vec![vec![0.0, 0.0]], vec![vec![2.0, 0.0]], vec![vec![2.0, 2.0]], vec![vec![0.0, 2.0]]];
+    let multiline = FeatureMultiLineString::new(lines);
+    let expected = FeaturePoint::new(vec![1.0, 1.0]);
+
+    assert_eq!(multiline.centroid(), Some(expected));
+  }
+
+  #[test]
+  fn test_multipolygon_weights_holes_correctly() {
+    // Polygon B has a hole removing roughly half its exterior area (16 -> 7);
+    // weighting by the exterior ring alone (the bug) would pull the combined
+    // centroid to (8.0, 8.0), but the hole-adjusted weight (4 vs 7) should
+    // land it at (70/11, 70/11) instead.
+    let polygon_a = vec![vec![vec![-1.0, -1.0], vec![1.0, -1.0], vec![1.0, 1.0], vec![-1.0, 1.0], vec![-1.0, -1.0]]];
+    let polygon_b = vec![
+      vec![vec![8.0, 8.0], vec![12.0, 8.0], vec![12.0, 12.0], vec![8.0, 12.0], vec![8.0, 8.0]],
+      vec![vec![8.5, 8.5], vec![11.5, 8.5], vec![11.5, 11.5], vec![8.5, 11.5], vec![8.5, 8.5]],
+    ];
+
+    let multipolygon = FeatureMultiPolygon::new(vec![polygon_a, polygon_b]);
+    let centroid = multipolygon.centroid().unwrap().coordinates();
+    let expected = 70.0 / 11.0;
+
+    assert!((centroid[0] - expected).abs() < 1e-6, "got {:?}", centroid);
+    assert!((centroid[1] - expected).abs() < 1e-6, "got {:?}", centroid);
+  }
+
+  #[test]
+  fn test_geometrycollection_picks_max_dimension() {
+    // A far-off line and point would badly skew the result if they weren't
+    // excluded in favor of the (higher-dimension) polygon.
+    let polygon = FeaturePolygon::new(vec![vec![vec![0.0, 0.0], vec![2.0, 0.0], vec![2.0, 2.0], vec![0.0, 2.0], vec![0.0, 0.0]]]);
+    let line = FeatureLineString::new(vec![vec![100.0, 100.0], vec![200.0, 100.0]]);
+    let point = FeaturePoint::new(vec![500.0, 500.0]);
+
+    let collection = FeatureGeometryCollection::new(vec![
+      Geometry::Polygon(polygon),
+      Geometry::LineString(line),
+      Geometry::Point(point),
+    ]);
+    let expected = FeaturePoint::new(vec![1.0, 1.0]);
+
+    assert_eq!(collection.centroid(), Some(expected));
+  }
+}
@@ -0,0 +1,226 @@
+///
+/// ## Overview
+///
+/// A polylabel-style "pole of inaccessibility" solver: the interior point of
+/// a polygon that sits farthest from the boundary, ideal for placing
+/// non-overlapping map labels.
+///
+/// Inspired by [mapbox/polylabel](https://github.com/mapbox/polylabel).
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use lodestone_point::FeaturePoint;
+use lodestone_polygon::FeaturePolygon;
+
+use super::{bounding_box, coords_distance, Centroid};
+
+/// Unsigned distance from `point` to the segment `a -> b`, via the same
+/// [`lodestone_line_distance`] edge math used elsewhere in this crate.
+fn point_to_segment_distance(point: &[f64], a: &[f64], b: &[f64]) -> f64 {
+  let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+
+  let closest = if dx == 0.0 && dy == 0.0 {
+    a.to_vec()
+  } else {
+    let t = (((point[0] - a[0]) * dx + (point[1] - a[1]) * dy) / (dx * dx + dy * dy))
+      .max(0.0)
+      .min(1.0);
+
+    vec![a[0] + t * dx, a[1] + t * dy]
+  };
+
+  coords_distance(point, &closest)
+}
+
+/// Even-odd ray test across every ring (exterior and holes), so holes
+/// correctly carve the interior out of the exterior.
+fn point_in_polygon(point: &[f64], rings: &[Vec<Vec<f64>>]) -> bool {
+  let mut inside = false;
+
+  for ring in rings {
+    let mut prev = &ring[ring.len() - 1];
+
+    for vertex in ring {
+      let (x0, y0) = (prev[0], prev[1]);
+      let (x1, y1) = (vertex[0], vertex[1]);
+
+      let crosses = ((y0 > point[1]) != (y1 > point[1]))
+        && (point[0] < (x1 - x0) * (point[1] - y0) / (y1 - y0) + x0);
+
+      if crosses {
+        inside = !inside;
+      }
+
+      prev = vertex;
+    }
+  }
+
+  inside
+}
+
+/// Signed distance from `(x, y)` to the polygon: the distance to the nearest
+/// edge over all rings, negated when the point falls outside the polygon.
+fn signed_distance(x: f64, y: f64, rings: &[Vec<Vec<f64>>]) -> f64 {
+  let point = vec![x, y];
+  let mut min_distance = std::f64::INFINITY;
+
+  for ring in rings {
+    let mut prev = &ring[ring.len() - 1];
+
+    for vertex in ring {
+      let distance = point_to_segment_distance(&point, prev, vertex);
+
+      if distance < min_distance {
+        min_distance = distance;
+      }
+
+      prev = vertex;
+    }
+  }
+
+  if point_in_polygon(&point, rings) {
+    min_distance
+  } else {
+    -min_distance
+  }
+}
+
+/// A candidate square cell in the quadtree search, ordered by its upper bound
+/// (`max`) so the max-heap always pops the most promising cell next.
+struct Cell {
+  x: f64,
+  y: f64,
+  half: f64,
+  d: f64,
+  max: f64,
+}
+
+impl Cell {
+  fn new(x: f64, y: f64, half: f64, rings: &[Vec<Vec<f64>>]) -> Cell {
+    let d = signed_distance(x, y, rings);
+    // Half-diagonal in meters (to the farther of an upper and a lower corner),
+    // so the bound is in the same unit as `d`.
+    let h = coords_distance(&[x, y], &[x + half, y + half])
+      .max(coords_distance(&[x, y], &[x + half, y - half]));
+    let max = d + h;
+
+    Cell { x, y, half, d, max }
+  }
+}
+
+impl PartialEq for Cell {
+  fn eq(&self, other: &Cell) -> bool {
+    self.max == other.max
+  }
+}
+
+impl Eq for Cell {}
+
+impl PartialOrd for Cell {
+  fn partial_cmp(&self, other: &Cell) -> Option<Ordering> {
+    self.max.partial_cmp(&other.max)
+  }
+}
+
+impl Ord for Cell {
+  fn cmp(&self, other: &Cell) -> Ordering {
+    self.partial_cmp(other).unwrap()
+  }
+}
+
+/// Finds the interior point of `polygon` farthest from its boundary, to
+/// within `precision_m` meters, via a quadtree search seeded by the centroid
+/// and a grid covering the bounding box.
+///
+/// Panics if `polygon`'s exterior ring is empty. This function has no
+/// `Option` to return the way `Centroid::centroid` does, so an empty polygon
+/// is treated as a precondition violation rather than a degenerate input.
+pub fn pole_of_inaccessibility(polygon: &FeaturePolygon, precision_m: f64) -> FeaturePoint {
+  let rings = polygon.coordinates();
+
+  // Resolved before indexing the exterior ring below, so an empty polygon
+  // panics here with a clear message naming the actual precondition, rather
+  // than an opaque out-of-bounds index panic further down. This does not
+  // eliminate the panic on empty input -- it only picks which one fires.
+  let centroid = polygon.centroid().expect("non-empty polygon has a centroid");
+
+  let (x_min, x_max, y_min, y_max) = bounding_box(&rings[0]);
+  let cell_size = (x_max - x_min).min(y_max - y_min);
+
+  if cell_size <= 0.0 {
+    return centroid;
+  }
+
+  let half = cell_size / 2.0;
+  let mut queue: BinaryHeap<Cell> = BinaryHeap::new();
+
+  let mut x = x_min;
+  while x < x_max {
+    let mut y = y_min;
+
+    while y < y_max {
+      queue.push(Cell::new(x + half, y + half, half, &rings));
+      y += cell_size;
+    }
+
+    x += cell_size;
+  }
+
+  let mut best = Cell::new(centroid.coordinates()[0], centroid.coordinates()[1], 0.0, &rings);
+
+  while let Some(cell) = queue.pop() {
+    if cell.d > best.d {
+      best = Cell::new(cell.x, cell.y, 0.0, &rings);
+    }
+
+    if cell.max - best.d <= precision_m {
+      continue;
+    }
+
+    let quarter = cell.half / 2.0;
+
+    for &(dx, dy) in &[(-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)] {
+      queue.push(Cell::new(cell.x + dx * quarter, cell.y + dy * quarter, quarter, &rings));
+    }
+  }
+
+  FeaturePoint::new(vec![best.x, best.y])
+}
+
+#[cfg(test)]
+mod tests {
+  use lodestone_polygon::FeaturePolygon;
+  use super::{point_in_polygon, pole_of_inaccessibility};
+
+  #[test]
+  fn test_square() {
+    // A square near the equator, where a degree of longitude and a degree of
+    // latitude are roughly the same real-world distance, so its pole of
+    // inaccessibility is its center, same as in the plane.
+    let ring = vec![vec![-1.0, -1.0], vec![1.0, -1.0], vec![1.0, 1.0], vec![-1.0, 1.0], vec![-1.0, -1.0]];
+    let polygon = FeaturePolygon::new(vec![ring]);
+
+    let point = pole_of_inaccessibility(&polygon, 1000.0).coordinates();
+
+    assert!(point[0].abs() < 0.01, "expected x near 0.0, got {}", point[0]);
+    assert!(point[1].abs() < 0.01, "expected y near 0.0, got {}", point[1]);
+  }
+
+  #[test]
+  fn test_concave_c_shape() {
+    // Same "C" shape as the InteriorPoint test: its plain area centroid
+    // falls inside the notch (outside the polygon), so the pole of
+    // inaccessibility must land on the solid material instead.
+    let ring = vec![
+      vec![0.0, 0.0], vec![10.0, 0.0], vec![10.0, 10.0], vec![0.0, 10.0],
+      vec![0.0, 6.0], vec![6.0, 6.0], vec![6.0, 4.0], vec![0.0, 4.0], vec![0.0, 0.0],
+    ];
+    let rings = vec![ring.clone()];
+    let polygon = FeaturePolygon::new(rings.clone());
+
+    let point = pole_of_inaccessibility(&polygon, 1000.0).coordinates();
+
+    assert!(point_in_polygon(&point, &rings), "expected {:?} to be inside the C-shape", point);
+  }
+}